@@ -37,7 +37,8 @@ use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{
-		ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf,
+		fungible::Credit, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Imbalance,
+		OnUnbalanced, TransformOrigin, VariantCountOf,
 	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
@@ -49,10 +50,10 @@ use frame_system::{
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
 use polkadot_runtime_common::{
-	xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
+	impls::ToAuthor, xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::Perbill;
+use sp_runtime::{Perbill, Percent};
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
 
@@ -168,11 +169,31 @@ impl pallet_balances::Config for Runtime {
 parameter_types! {
 	/// Relay Chain `TransactionByteFee` / 10
 	pub const TransactionByteFee: Balance = 10 * MICRO_UNIT;
+	/// Share of each transaction fee (tips excluded) paid to the block author.
+	pub const AuthorFeeShare: Percent = Percent::from_percent(20);
+}
+
+/// Pays [`AuthorFeeShare`] of the transaction fee plus the whole tip to the block author and burns
+/// the rest of the fee, so collators are rewarded for the transactions they include.
+/// A payout below the existential deposit to a not yet existing author account is burned too.
+pub struct DealWithFees;
+impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
+	fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let author_share = u32::from(AuthorFeeShare::get().deconstruct());
+			// Dropping the remaining credit decreases the total issuance, i.e. burns it.
+			let (mut to_author, _burned) = fees.ration(author_share, 100 - author_share);
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut to_author);
+			}
+			ToAuthor::<Runtime>::on_unbalanced(to_author);
+		}
+	}
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+	type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>;
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
@@ -315,3 +336,104 @@ impl pallet_parachain_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_parachain_template::weights::SubstrateWeight<Runtime>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{MILLI_UNIT, UNIT};
+	use codec::Encode;
+	use frame_support::traits::{
+		fungible::{Balanced, Inspect, Mutate},
+		tokens::{Fortitude, Precision, Preservation},
+	};
+	use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+	use sp_runtime::{BoundedVec, BuildStorage, Digest, DigestItem};
+
+	fn author() -> AccountId {
+		AccountId::from([1u8; 32])
+	}
+
+	fn payer() -> AccountId {
+		AccountId::from([2u8; 32])
+	}
+
+	/// Builds externalities with a funded payer and, if given, `author` set as the author of the
+	/// current block through an Aura pre-runtime digest.
+	fn new_test_ext(author: Option<AccountId>) -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| {
+			let mut digest = Digest::default();
+			if let Some(author) = author {
+				let aura_id = AuraId::from(sp_core::sr25519::Public::from_raw([0u8; 32]));
+				pallet_aura::Authorities::<Runtime>::put(BoundedVec::truncate_from(vec![aura_id]));
+				pallet_session::Validators::<Runtime>::put(vec![author]);
+				digest.push(DigestItem::PreRuntime(AURA_ENGINE_ID, Slot::from(0u64).encode()));
+			}
+			System::initialize(&1, &Default::default(), &digest);
+			Balances::set_balance(&payer(), UNIT);
+		});
+		ext
+	}
+
+	/// Withdraws `fee` and `tip` from the payer and hands both credits to [`DealWithFees`].
+	fn charge(fee: Balance, tip: Balance) {
+		let withdraw = |amount| {
+			Balances::withdraw(
+				&payer(),
+				amount,
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)
+			.unwrap()
+		};
+		DealWithFees::on_unbalanceds([withdraw(fee), withdraw(tip)].into_iter());
+	}
+
+	#[test]
+	fn author_receives_fee_share_and_tip() {
+		new_test_ext(Some(author())).execute_with(|| {
+			Balances::set_balance(&author(), UNIT);
+			let issuance = Balances::total_issuance();
+
+			charge(10 * MILLI_UNIT, 3 * MILLI_UNIT);
+
+			// 20% of the fee plus the whole tip.
+			assert_eq!(Balances::balance(&author()), UNIT + 2 * MILLI_UNIT + 3 * MILLI_UNIT);
+			// The other 80% of the fee is burned.
+			assert_eq!(Balances::total_issuance(), issuance - 8 * MILLI_UNIT);
+			System::assert_has_event(
+				pallet_balances::Event::Deposit { who: author(), amount: 5 * MILLI_UNIT }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn fees_and_tips_are_burned_without_author() {
+		new_test_ext(None).execute_with(|| {
+			let issuance = Balances::total_issuance();
+
+			charge(10 * MILLI_UNIT, 3 * MILLI_UNIT);
+
+			assert_eq!(Balances::total_issuance(), issuance - 13 * MILLI_UNIT);
+		});
+	}
+
+	#[test]
+	fn payout_below_existential_deposit_to_new_author_is_burned() {
+		new_test_ext(Some(author())).execute_with(|| {
+			let issuance = Balances::total_issuance();
+
+			// 200 µUNIT of fee share plus a 100 µUNIT tip is below the existential deposit.
+			charge(MILLI_UNIT, MILLI_UNIT / 10);
+
+			assert_eq!(Balances::balance(&author()), 0);
+			assert!(!System::events().iter().any(|record| matches!(
+				&record.event,
+				RuntimeEvent::Balances(pallet_balances::Event::Deposit { who, .. }) if *who == author()
+			)));
+			assert_eq!(Balances::total_issuance(), issuance - MILLI_UNIT - MILLI_UNIT / 10);
+		});
+	}
+}